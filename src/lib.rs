@@ -62,17 +62,163 @@ pub use eyre::{Report, Result};
 use eyre::EyreHandler;
 
 use std::error::Error;
+use std::panic::Location;
+use std::sync::Arc;
 
-/// A custom context type for minimal error reporting via `eyre`
+/// A callback responsible for rendering a single error in the chain.
+///
+/// It is invoked once per error, in order from the outermost error (`index`
+/// `0`) down to the root cause, and is free to emit its own separators — for
+/// example prefixing every error but the first with `": "` to reproduce the
+/// default report.
+pub type FormatFn = Arc<
+    dyn Fn(usize, &(dyn Error + 'static), &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+        + Send
+        + Sync,
+>;
+
+/// Build the callback that joins each error with the given separator,
+/// reproducing the classic `outer: middle: cause` report.
+fn default_format(separator: &'static str) -> FormatFn {
+    Arc::new(move |index, error, f| {
+        if index == 0 {
+            write!(f, "{}", error)
+        } else {
+            write!(f, "{}{}", separator, error)
+        }
+    })
+}
+
+/// A one-line note or suggestion attached to a report via the [`Section`] trait.
 #[derive(Debug)]
+enum Help {
+    Note(String),
+    Suggestion(String),
+}
+
+/// A custom context type for minimal error reporting via `eyre`
 pub struct Handler {
-    separator: &'static str,
+    format: FormatFn,
+    track_location: bool,
+    location: Option<&'static Location<'static>>,
+    sections: Vec<Help>,
+    #[cfg(feature = "json")]
+    json: bool,
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<std::backtrace::Backtrace>,
+}
+
+impl core::fmt::Debug for Handler {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Handler").finish()
+    }
 }
 
 impl Handler {
-    /// Construct a new context which uses the given separator
-    fn new(separator: &'static str) -> Self {
-        Self { separator }
+    /// Construct a new context which renders each error with the given callback
+    fn new(format: FormatFn) -> Self {
+        Self {
+            format,
+            track_location: false,
+            location: None,
+            sections: Vec::new(),
+            #[cfg(feature = "json")]
+            json: false,
+            #[cfg(feature = "backtrace")]
+            backtrace: None,
+        }
+    }
+
+    /// Construct a new context which emits the error chain as a single JSON
+    /// object instead of a colon-delimited string.
+    #[cfg(feature = "json")]
+    fn json() -> Self {
+        Self {
+            format: default_format(DEFAULT_SEPARATOR),
+            track_location: false,
+            location: None,
+            sections: Vec::new(),
+            json: true,
+            #[cfg(feature = "backtrace")]
+            backtrace: None,
+        }
+    }
+
+    /// Construct a new context which carries the backtrace captured at report
+    /// construction time.
+    #[cfg(feature = "backtrace")]
+    fn with_backtrace(format: FormatFn, backtrace: std::backtrace::Backtrace) -> Self {
+        Self {
+            format,
+            track_location: false,
+            location: None,
+            sections: Vec::new(),
+            #[cfg(feature = "json")]
+            json: false,
+            backtrace: Some(backtrace),
+        }
+    }
+
+    /// Append any attached notes and suggestions after the error chain, e.g.
+    /// ` (note: check config) (suggestion: run with --init)`.
+    fn write_sections(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for section in &self.sections {
+            match section {
+                Help::Note(note) => write!(f, " (note: {})", note)?,
+                Help::Suggestion(suggestion) => write!(f, " (suggestion: {})", suggestion)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Append the captured creation location (e.g. ` (at src/foo.rs:42)`) to the
+    /// end of the one-line report, when location tracking was enabled on the
+    /// builder and a location was recorded.
+    fn write_location(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.track_location {
+            if let Some(location) = self.location {
+                write!(f, " (at {}:{})", location.file(), location.line())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Render the flattened error chain as a single line of JSON, e.g.
+    /// `{"causes":["middle","cause"],"error":"outer"}`.
+    #[cfg(feature = "json")]
+    fn write_json(
+        &self,
+        error: &(dyn Error + 'static),
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        let mut errors = std::iter::successors(Some(error), |e| (*e).source());
+        let head = errors.next().expect("error chain is never empty").to_string();
+        let causes = errors.map(|e| e.to_string()).collect::<Vec<_>>();
+
+        let report = serde_json::json!({
+            "error": head,
+            "causes": causes,
+        });
+
+        write!(f, "{}", report)
+    }
+
+    /// Append the captured backtrace on subsequent lines when it was actually
+    /// recorded (i.e. `RUST_BACKTRACE` was set), leaving the default report
+    /// untouched.
+    #[cfg(feature = "backtrace")]
+    fn write_backtrace(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use std::backtrace::BacktraceStatus;
+
+        if let Some(backtrace) = &self.backtrace {
+            if backtrace.status() == BacktraceStatus::Captured {
+                write!(f, "\n\n{}", backtrace)?;
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -82,26 +228,141 @@ impl EyreHandler for Handler {
         error: &(dyn Error + 'static),
         f: &mut core::fmt::Formatter<'_>,
     ) -> core::fmt::Result {
+        #[cfg(feature = "json")]
+        if self.json {
+            return self.write_json(error, f);
+        }
+
         if f.alternate() {
-            return core::fmt::Debug::fmt(error, f);
+            core::fmt::Debug::fmt(error, f)?;
+
+            #[cfg(feature = "backtrace")]
+            self.write_backtrace(f)?;
+
+            return Ok(());
+        }
+
+        let errors = std::iter::successors(Some(error), |e| (*e).source());
+        for (index, error) in errors.enumerate() {
+            (self.format)(index, error, f)?;
         }
 
-        write!(f, "{}", error)?;
+        self.write_sections(f)?;
+        self.write_location(f)?;
 
-        if let Some(cause) = error.source() {
-            let errors = std::iter::successors(Some(cause), |e| (*e).source());
-            for error in errors {
-                write!(f, "{}{}", self.separator, error)?;
-            }
+        Ok(())
+    }
+
+    fn display(
+        &self,
+        error: &(dyn Error + 'static),
+        f: &mut core::fmt::Formatter<'_>,
+    ) -> core::fmt::Result {
+        #[cfg(feature = "json")]
+        if self.json {
+            return self.write_json(error, f);
         }
 
+        let errors = std::iter::successors(Some(error), |e| (*e).source());
+        for (index, error) in errors.enumerate() {
+            (self.format)(index, error, f)?;
+        }
+
+        self.write_sections(f)?;
+        self.write_location(f)?;
+
         Ok(())
     }
+
+    fn track_caller(&mut self, location: &'static Location<'static>) {
+        self.location = Some(location);
+    }
 }
 
 /// The default separator used to delimitate errors.
 const DEFAULT_SEPARATOR: &str = ": ";
 
+/// Builder for configuring how the one-line report is rendered.
+///
+/// Obtain one with [`config`], tweak it, then call [`install`](HandlerBuilder::install)
+/// to register the hook. The default builder reproduces the `outer: middle: cause`
+/// report; [`format`](HandlerBuilder::format) replaces the per-error rendering
+/// entirely, while [`separator`](HandlerBuilder::separator) only swaps the string
+/// joining successive errors.
+pub struct HandlerBuilder {
+    format: FormatFn,
+    track_location: bool,
+}
+
+impl core::fmt::Debug for HandlerBuilder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("HandlerBuilder").finish()
+    }
+}
+
+impl Default for HandlerBuilder {
+    fn default() -> Self {
+        Self {
+            format: default_format(DEFAULT_SEPARATOR),
+            track_location: false,
+        }
+    }
+}
+
+impl HandlerBuilder {
+    /// Join successive errors in the chain with the given separator.
+    pub fn separator(mut self, separator: &'static str) -> Self {
+        self.format = default_format(separator);
+        self
+    }
+
+    /// Render each error in the chain with a custom callback.
+    ///
+    /// The callback receives the error's position in the chain (the outermost
+    /// error is `0`), the error itself, and the formatter, and is responsible
+    /// for emitting any separators it wants.
+    pub fn format<F>(mut self, format: F) -> Self
+    where
+        F: Fn(usize, &(dyn Error + 'static), &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.format = Arc::new(format);
+        self
+    }
+
+    /// Append the location where the report was created (e.g. ` (at src/foo.rs:42)`)
+    /// to the end of the one-line report.
+    pub fn track_location(mut self, track_location: bool) -> Self {
+        self.track_location = track_location;
+        self
+    }
+
+    /// Install the configured hook as the global error report hook.
+    ///
+    /// Only the first install will succeed. Calling this after another report
+    /// handler has been installed will cause an error.
+    pub fn install(self) -> Result<()> {
+        let format = self.format;
+        let track_location = self.track_location;
+        crate::eyre::set_hook(Box::new(move |_| {
+            let mut handler = Handler::new(format.clone());
+            handler.track_location = track_location;
+            Box::new(handler)
+        }))?;
+
+        Ok(())
+    }
+}
+
+/// Start configuring a custom `oneline-eyre` hook.
+///
+/// See [`HandlerBuilder`] for the available options.
+pub fn config() -> HandlerBuilder {
+    HandlerBuilder::default()
+}
+
 /// Install the `oneline-eyre` hook as the global error report hook,
 /// using `: ` `s a separator.
 ///
@@ -116,7 +377,7 @@ const DEFAULT_SEPARATOR: &str = ": ";
 /// function _must_ be called before any `eyre::Report`s are constructed to
 /// prevent the default handler from being installed.
 pub fn install() -> Result<()> {
-    install_custom(DEFAULT_SEPARATOR)
+    config().install()
 }
 
 /// Install the `oneline-eyre` hook as the global error report hook,
@@ -133,7 +394,92 @@ pub fn install() -> Result<()> {
 /// function _must_ be called before any `eyre::Report`s are constructed to
 /// prevent the default handler from being installed.
 pub fn install_custom(separator: &'static str) -> Result<()> {
-    crate::eyre::set_hook(Box::new(move |_| Box::new(Handler::new(separator))))?;
+    config().separator(separator).install()
+}
+
+/// Install the `oneline-eyre` hook as the global error report hook, emitting
+/// each report as a single JSON object.
+///
+/// # Details
+///
+/// Instead of the colon-delimited `outer: middle: cause` string, reports are
+/// rendered as one line of JSON of the shape
+/// `{"causes":["middle","cause"],"error":"outer"}`, which keeps the "one line"
+/// philosophy while staying grep- and ingest-friendly for log aggregators.
+///
+/// Only the first install will succeed. Calling this function after another
+/// report handler has been installed will cause an error. **Note**: This
+/// function _must_ be called before any `eyre::Report`s are constructed to
+/// prevent the default handler from being installed.
+#[cfg(feature = "json")]
+pub fn install_json() -> Result<()> {
+    crate::eyre::set_hook(Box::new(|_| Box::new(Handler::json())))?;
 
     Ok(())
 }
+
+/// Install the `oneline-eyre` hook as the global error report hook, capturing a
+/// backtrace for each report.
+///
+/// # Details
+///
+/// Like [`install`], but each constructed `eyre::Report` additionally captures a
+/// [`std::backtrace::Backtrace`]. The default one-line report is unaffected; the
+/// backtrace is only rendered on subsequent lines when the report is formatted
+/// with the alternate flag (`{:#?}`) and `RUST_BACKTRACE` was set so that the
+/// backtrace was actually captured.
+///
+/// Only the first install will succeed. Calling this function after another
+/// report handler has been installed will cause an error.
+#[cfg(feature = "backtrace")]
+pub fn install_with_backtrace() -> Result<()> {
+    let format = default_format(DEFAULT_SEPARATOR);
+    crate::eyre::set_hook(Box::new(move |_| {
+        Box::new(Handler::with_backtrace(
+            format.clone(),
+            std::backtrace::Backtrace::capture(),
+        ))
+    }))?;
+
+    Ok(())
+}
+
+/// Extension trait for attaching one-line notes and suggestions to a report.
+///
+/// Inspired by `color-eyre`'s `Section` trait, but kept deliberately minimal:
+/// each note or suggestion is a single string rendered inline after the error
+/// chain, e.g. `outer: middle: cause (note: check config) (suggestion: run with --init)`.
+/// The attached text is stored on the [`Handler`], so it is only rendered when
+/// the `oneline-eyre` hook is installed.
+pub trait Section<T> {
+    /// Attach a note to the report, rendered as ` (note: ...)`.
+    fn note(self, note: impl Into<String>) -> Result<T>;
+
+    /// Attach a suggestion to the report, rendered as ` (suggestion: ...)`.
+    fn suggestion(self, suggestion: impl Into<String>) -> Result<T>;
+}
+
+impl<T, E> Section<T> for std::result::Result<T, E>
+where
+    E: Into<Report>,
+{
+    fn note(self, note: impl Into<String>) -> Result<T> {
+        self.map_err(|error| {
+            let mut report = error.into();
+            if let Some(handler) = report.handler_mut().downcast_mut::<Handler>() {
+                handler.sections.push(Help::Note(note.into()));
+            }
+            report
+        })
+    }
+
+    fn suggestion(self, suggestion: impl Into<String>) -> Result<T> {
+        self.map_err(|error| {
+            let mut report = error.into();
+            if let Some(handler) = report.handler_mut().downcast_mut::<Handler>() {
+                handler.sections.push(Help::Suggestion(suggestion.into()));
+            }
+            report
+        })
+    }
+}
@@ -0,0 +1,17 @@
+#![cfg(feature = "json")]
+
+use oneline_eyre::eyre::{eyre, Report, WrapErr};
+
+#[test]
+fn ok() {
+    oneline_eyre::install_json().unwrap();
+
+    let error: Report = eyre!("cause");
+    let wrapped: Result<(), _> = Err(error).wrap_err("middle").wrap_err("outer");
+
+    let output = format!("{:?}", wrapped.unwrap_err());
+    assert_eq!(
+        output,
+        r#"{"causes":["middle","cause"],"error":"outer"}"#
+    );
+}
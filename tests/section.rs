@@ -0,0 +1,20 @@
+use oneline_eyre::eyre::{eyre, Report, WrapErr};
+use oneline_eyre::Section;
+
+#[test]
+fn ok() {
+    oneline_eyre::install().unwrap();
+
+    let error: Report = eyre!("cause");
+    let wrapped: Result<(), _> = Err(error)
+        .wrap_err("middle")
+        .wrap_err("outer")
+        .note("check config")
+        .suggestion("run with --init");
+
+    let output = format!("{:?}", wrapped.unwrap_err());
+    assert_eq!(
+        output,
+        "outer: middle: cause (note: check config) (suggestion: run with --init)"
+    );
+}